@@ -139,6 +139,90 @@ impl TransitionAngle {
     }
 }
 
+/// Writer for a _viewer preferences dictionary_.
+///
+/// This struct is created by [`Catalog::viewer_preferences`].
+pub struct ViewerPreferences<'a> {
+    dict: Dict<'a>,
+}
+
+impl<'a> ViewerPreferences<'a> {
+    pub(crate) fn new(obj: Obj<'a>) -> Self {
+        let dict = obj.dict();
+        Self { dict }
+    }
+
+    /// Write the `/HideToolbar` attribute to set whether the viewer should
+    /// hide its toolbars while the document is open.
+    pub fn hide_toolbar(&mut self, hide: bool) -> &mut Self {
+        self.pair(Name(b"HideToolbar"), hide);
+        self
+    }
+
+    /// Write the `/HideMenubar` attribute to set whether the viewer should
+    /// hide its menu bar while the document is open.
+    pub fn hide_menubar(&mut self, hide: bool) -> &mut Self {
+        self.pair(Name(b"HideMenubar"), hide);
+        self
+    }
+
+    /// Write the `/HideWindowUI` attribute to set whether the viewer should
+    /// hide user interface elements such as scroll bars while the document
+    /// is open, leaving only the page's contents visible.
+    pub fn hide_window_ui(&mut self, hide: bool) -> &mut Self {
+        self.pair(Name(b"HideWindowUI"), hide);
+        self
+    }
+
+    /// Write the `/FitWindow` attribute to set whether the viewer should
+    /// resize its window to fit the size of the first displayed page.
+    pub fn fit_window(&mut self, fit: bool) -> &mut Self {
+        self.pair(Name(b"FitWindow"), fit);
+        self
+    }
+
+    /// Write the `/NonFullScreenPageMode` attribute to set which page mode
+    /// the viewer should revert to when a full-screen presentation is
+    /// exited by the user.
+    pub fn non_full_screen_page_mode(&mut self, mode: PageMode) -> &mut Self {
+        self.pair(Name(b"NonFullScreenPageMode"), mode.to_name());
+        self
+    }
+}
+
+deref!('a, ViewerPreferences<'a> => Dict<'a>, dict);
+
+/// How the document should be displayed when opened, written as the
+/// `/PageMode` attribute of the catalog via [`Catalog::page_mode`].
+pub enum PageMode {
+    /// Neither the document outline nor thumbnail images are visible.
+    UseNone,
+    /// The document outline is visible.
+    UseOutlines,
+    /// Thumbnail images are visible.
+    UseThumbs,
+    /// The document is opened in full-screen presentation mode, with no
+    /// menu bar, window controls, or any other window visible.
+    FullScreen,
+    /// Optional content group panels are visible. (1.5+)
+    UseOC,
+    /// The attachments panel is visible. (1.6+)
+    UseAttachments,
+}
+
+impl PageMode {
+    pub(crate) fn to_name(self) -> Name<'static> {
+        match self {
+            Self::UseNone => Name(b"UseNone"),
+            Self::UseOutlines => Name(b"UseOutlines"),
+            Self::UseThumbs => Name(b"UseThumbs"),
+            Self::FullScreen => Name(b"FullScreen"),
+            Self::UseOC => Name(b"UseOC"),
+            Self::UseAttachments => Name(b"UseAttachments"),
+        }
+    }
+}
+
 /// Writer for an _action dictionary_.
 ///
 /// This struct is created by [`Annotation::action`].
@@ -198,10 +282,71 @@ impl<'a> Action<'a> {
         self.pair(Name(b"IsMap"), map);
         self
     }
+
+    /// Write the `/N` attribute to set the named action to perform for a
+    /// `Named`-type action. Accepts an arbitrary name so viewer-specific
+    /// actions (e.g. `GoBack`, `GoForward`, `Print`, `SaveAs`) can be used
+    /// in addition to the standard ones in [`NamedActionType`].
+    pub fn named(&mut self, name: Name) -> &mut Self {
+        self.pair(Name(b"N"), name);
+        self
+    }
+
+    /// Write the `/JS` attribute to set the script to run for a
+    /// `JavaScript`-type action.
+    pub fn js(&mut self, script: Str) -> &mut Self {
+        self.pair(Name(b"JS"), script);
+        self
+    }
+
+    /// Write the `/JS` attribute as an indirect reference to a text stream,
+    /// for scripts too long to embed as a literal string.
+    pub fn js_ref(&mut self, script: Ref) -> &mut Self {
+        self.pair(Name(b"JS"), script);
+        self
+    }
+
+    /// A convenience that sets this to a `JavaScript`-type action silently
+    /// printing the document, equivalent to setting [`Self::action_type`] to
+    /// [`ActionType::JavaScript`] and [`Self::js`] to
+    /// `this.print({bUI:false,bSilent:true,bShrinkToFit:true});`.
+    pub fn print_silently(&mut self) -> &mut Self {
+        self.action_type(ActionType::JavaScript);
+        self.js(Str(
+            b"this.print({bUI:false,bSilent:true,bShrinkToFit:true});",
+        ))
+    }
+
+    /// Start writing the `/Next` attribute to chain one or more actions that
+    /// are executed in order after this one has completed. Each chained
+    /// action may in turn declare its own `/Next` actions.
+    pub fn next(&mut self) -> Actions<'_> {
+        Actions::start(self.key(Name(b"Next")))
+    }
 }
 
 deref!('a, Action<'a> => Dict<'a>, dict);
 
+/// Writer for an array of chained _actions_.
+///
+/// This struct is created by [`Action::next`].
+pub struct Actions<'a> {
+    array: Array<'a>,
+}
+
+impl<'a> Actions<'a> {
+    pub(crate) fn start(obj: Obj<'a>) -> Self {
+        Self { array: obj.array() }
+    }
+
+    /// Start writing a new action in the chain.
+    pub fn push(&mut self) -> Action<'_> {
+        Action::new(self.array.push())
+    }
+}
+
+deref!('a, Actions<'a> => Array<'a>, array);
+
 /// What kind of action to perform.
 pub enum ActionType {
     /// Go to a destination in the document.
@@ -212,6 +357,11 @@ pub enum ActionType {
     Launch,
     /// Open a URI.
     Uri,
+    /// Execute an action that is predefined by the viewer, such as going to
+    /// the next or previous page.
+    Named,
+    /// Execute a JavaScript script.
+    JavaScript,
 }
 
 impl ActionType {
@@ -221,6 +371,88 @@ impl ActionType {
             Self::RemoteGoTo => Name(b"GoToR"),
             Self::Launch => Name(b"Launch"),
             Self::Uri => Name(b"URI"),
+            Self::Named => Name(b"Named"),
+            Self::JavaScript => Name(b"JavaScript"),
+        }
+    }
+}
+
+/// One of the standard named actions defined by the PDF specification for
+/// [`ActionType::Named`]. Write it with [`Action::named`] by converting it
+/// to a [`Name`] via [`Self::to_name`].
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub enum NamedActionType {
+    /// Go to the next page.
+    NextPage,
+    /// Go to the previous page.
+    PrevPage,
+    /// Go to the first page.
+    FirstPage,
+    /// Go to the last page.
+    LastPage,
+}
+
+impl NamedActionType {
+    /// Return the name of this action as defined by the PDF specification.
+    pub fn to_name(self) -> Name<'static> {
+        match self {
+            Self::NextPage => Name(b"NextPage"),
+            Self::PrevPage => Name(b"PrevPage"),
+            Self::FirstPage => Name(b"FirstPage"),
+            Self::LastPage => Name(b"LastPage"),
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn named_action_type_to_name() {
+        assert_eq!(NamedActionType::NextPage.to_name().0, b"NextPage");
+        assert_eq!(NamedActionType::PrevPage.to_name().0, b"PrevPage");
+        assert_eq!(NamedActionType::FirstPage.to_name().0, b"FirstPage");
+        assert_eq!(NamedActionType::LastPage.to_name().0, b"LastPage");
+    }
+
+    #[test]
+    fn page_mode_to_name() {
+        assert_eq!(PageMode::FullScreen.to_name().0, b"FullScreen");
+    }
+
+    #[test]
+    fn print_silently_writes_exact_script() {
+        let mut pdf = Pdf::new();
+        Action::new(pdf.indirect(Ref::new(1))).print_silently();
+        let buf = pdf.finish();
+        let text = String::from_utf8(buf).unwrap();
+        assert!(text.contains("/S /JavaScript"));
+        assert!(text.contains("this.print({bUI:false,bSilent:true,bShrinkToFit:true});"));
+    }
+
+    #[test]
+    fn next_chains_multiple_actions_in_order() {
+        let mut pdf = Pdf::new();
+        let mut action = Action::new(pdf.indirect(Ref::new(1)));
+        action.action_type(ActionType::GoTo);
+        let mut chain = action.next();
+        chain
+            .push()
+            .action_type(ActionType::Named)
+            .named(Name(b"NextPage"));
+        chain
+            .push()
+            .action_type(ActionType::Named)
+            .named(Name(b"PrevPage"));
+        drop(chain);
+        drop(action);
+
+        let text = String::from_utf8(pdf.finish()).unwrap();
+        let next_pos = text.find("/Next").unwrap();
+        let first = text.find("/N /NextPage").unwrap();
+        let second = text.find("/N /PrevPage").unwrap();
+        assert!(next_pos < first);
+        assert!(first < second);
+    }
+}
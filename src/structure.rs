@@ -0,0 +1,64 @@
+use super::*;
+
+/// Writer for the document _catalog_.
+///
+/// This struct is created by [`Pdf::catalog`].
+pub struct Catalog<'a> {
+    dict: Dict<'a>,
+}
+
+impl<'a> Catalog<'a> {
+    pub(crate) fn new(obj: Obj<'a>) -> Self {
+        let mut dict = obj.dict();
+        dict.pair(Name(b"Type"), Name(b"Catalog"));
+        Self { dict }
+    }
+
+    /// Write the `/PageMode` attribute to set which navigational and
+    /// interface elements the viewer should display when the document is
+    /// opened.
+    pub fn page_mode(&mut self, mode: PageMode) -> &mut Self {
+        self.pair(Name(b"PageMode"), mode.to_name());
+        self
+    }
+
+    /// Start writing the `/ViewerPreferences` dictionary to control how the
+    /// viewer presents the document.
+    pub fn viewer_preferences(&mut self) -> ViewerPreferences<'_> {
+        ViewerPreferences::new(self.key(Name(b"ViewerPreferences")))
+    }
+}
+
+deref!('a, Catalog<'a> => Dict<'a>, dict);
+
+/// Writer for a _page object_.
+///
+/// This struct is created by [`Pdf::page`].
+pub struct Page<'a> {
+    dict: Dict<'a>,
+}
+
+impl<'a> Page<'a> {
+    pub(crate) fn new(obj: Obj<'a>) -> Self {
+        let mut dict = obj.dict();
+        dict.pair(Name(b"Type"), Name(b"Page"));
+        Self { dict }
+    }
+
+    /// Start writing the `/Trans` attribute to set the transition effect
+    /// that is used when moving to the next page.
+    pub fn trans(&mut self) -> Transition<'_> {
+        Transition::new(self.key(Name(b"Trans")))
+    }
+
+    /// Write the `/Dur` attribute to set the number of seconds this page is
+    /// displayed before automatically advancing to the next one. This is
+    /// independent of the transition's own `/D` duration and is what drives
+    /// a self-running presentation.
+    pub fn duration(&mut self, seconds: f32) -> &mut Self {
+        self.pair(Name(b"Dur"), seconds);
+        self
+    }
+}
+
+deref!('a, Page<'a> => Dict<'a>, dict);